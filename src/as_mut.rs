@@ -0,0 +1,184 @@
+/// Implement [`AsMut`] for a struct.
+///
+/// The first argument is that of the newtype struct to create the impl for and the second is the
+/// `AsMut` target type. The third argument is required for non-newtype structs and is the name of
+/// the field to reference.
+///
+/// Use the `ref <type>` form to forward through the inner field's own [`AsMut`] implementation,
+/// which is useful for exposing `AsMut<str>` on a `String` field, for example.
+///
+/// A generics list can be given by prefixing the type with a bracketed list (e.g. `['a]` or
+/// `[T: Clone]`), allowing this macro to be used on generic, lifetime-parameterized, or bounded
+/// structs.
+///
+/// Unlike [`DerefMut`](std::ops::DerefMut), a type can implement `AsMut` for multiple targets, so
+/// this macro can be invoked more than once for the same struct.
+///
+/// Also see [`impl_as_ref`].
+///
+/// # Examples
+/// ```
+/// use impl_more::impl_as_mut;
+///
+/// struct Foo(String);
+/// impl_as_mut!(Foo, String);
+///
+/// let mut foo = Foo("bar".to_owned());
+/// let bar: &mut String = foo.as_mut();
+/// bar.push('!');
+/// assert_eq!(foo.0, "bar!");
+/// ```
+///
+/// ```
+/// use impl_more::impl_as_mut;
+///
+/// struct Foo { msg: String }
+/// impl_as_mut!(Foo, String, msg);
+///
+/// let mut foo = Foo { msg: "bar".to_owned() };
+/// let bar: &mut String = foo.as_mut();
+/// bar.push('!');
+/// assert_eq!(foo.msg, "bar!");
+/// ```
+///
+/// Forward through the field's own `AsMut` implementation to expose a different target type:
+/// ```
+/// use impl_more::impl_as_mut;
+///
+/// struct Foo(String);
+/// impl_as_mut!(Foo, ref str);
+///
+/// let mut foo = Foo("bar".to_owned());
+/// let bar: &mut str = foo.as_mut();
+/// assert_eq!(bar, "bar");
+/// ```
+///
+/// Generic structs, including bounded ones, are supported by prefixing the type with its
+/// bracketed generics list:
+///
+/// ```
+/// use impl_more::impl_as_mut;
+///
+/// struct Wrapper<T: Clone>(T);
+/// impl_as_mut!([T: Clone] Wrapper<T>, T);
+///
+/// let mut foo = Wrapper(1_i32);
+/// let bar: &mut i32 = foo.as_mut();
+/// assert_eq!(*bar, 1);
+/// ```
+///
+/// [`AsMut`]: std::convert::AsMut
+/// [`impl_as_ref`]: crate::impl_as_ref
+#[macro_export]
+macro_rules! impl_as_mut {
+    ([$($generics:tt)*] $ty:ty, ref $target:ty) => {
+        impl<$($generics)*> ::core::convert::AsMut<$target> for $ty {
+            fn as_mut(&mut self) -> &mut $target {
+                ::core::convert::AsMut::as_mut(&mut self.0)
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $ty:ty, ref $target:ty, $field:ident) => {
+        impl<$($generics)*> ::core::convert::AsMut<$target> for $ty {
+            fn as_mut(&mut self) -> &mut $target {
+                ::core::convert::AsMut::as_mut(&mut self.$field)
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $ty:ty, $target:ty) => {
+        impl<$($generics)*> ::core::convert::AsMut<$target> for $ty {
+            fn as_mut(&mut self) -> &mut $target {
+                &mut self.0
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $ty:ty, $target:ty, $field:ident) => {
+        impl<$($generics)*> ::core::convert::AsMut<$target> for $ty {
+            fn as_mut(&mut self) -> &mut $target {
+                &mut self.$field
+            }
+        }
+    };
+
+    ($ty:ty, ref $target:ty) => {
+        impl ::core::convert::AsMut<$target> for $ty {
+            fn as_mut(&mut self) -> &mut $target {
+                ::core::convert::AsMut::as_mut(&mut self.0)
+            }
+        }
+    };
+
+    ($ty:ty, ref $target:ty, $field:ident) => {
+        impl ::core::convert::AsMut<$target> for $ty {
+            fn as_mut(&mut self) -> &mut $target {
+                ::core::convert::AsMut::as_mut(&mut self.$field)
+            }
+        }
+    };
+
+    ($ty:ty, $target:ty) => {
+        impl ::core::convert::AsMut<$target> for $ty {
+            fn as_mut(&mut self) -> &mut $target {
+                &mut self.0
+            }
+        }
+    };
+
+    ($ty:ty, $target:ty, $field:ident) => {
+        impl ::core::convert::AsMut<$target> for $ty {
+            fn as_mut(&mut self) -> &mut $target {
+                &mut self.$field
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    struct Foo1(String);
+    impl_as_mut!(Foo1, String);
+
+    #[test]
+    fn foo1_impls() {
+        let mut foo = Foo1("bar".to_owned());
+        let bar: &mut String = foo.as_mut();
+        bar.push('!');
+        assert_eq!(foo.0, "bar!");
+    }
+
+    struct Foo2 {
+        msg: String,
+    }
+    impl_as_mut!(Foo2, String, msg);
+
+    #[test]
+    fn foo2_impls() {
+        let mut foo = Foo2 { msg: "bar".to_owned() };
+        let bar: &mut String = foo.as_mut();
+        bar.push('!');
+        assert_eq!(foo.msg, "bar!");
+    }
+
+    struct Foo3(String);
+    impl_as_mut!(Foo3, ref str);
+
+    #[test]
+    fn foo3_impls() {
+        let mut foo = Foo3("bar".to_owned());
+        let bar: &mut str = foo.as_mut();
+        assert_eq!(bar, "bar");
+    }
+
+    struct Wrapper<T: Clone>(T);
+    impl_as_mut!([T: Clone] Wrapper<T>, T);
+
+    #[test]
+    fn wrapper_impls() {
+        let mut foo = Wrapper(1_i32);
+        let bar: &mut i32 = foo.as_mut();
+        assert_eq!(*bar, 1);
+    }
+}
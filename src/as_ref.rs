@@ -0,0 +1,180 @@
+/// Implement [`AsRef`] for a struct.
+///
+/// The first argument is that of the newtype struct to create the impl for and the second is the
+/// `AsRef` target type. The third argument is required for non-newtype structs and is the name of
+/// the field to reference.
+///
+/// Use the `ref <type>` form to forward through the inner field's own [`AsRef`] implementation,
+/// which is useful for exposing `AsRef<str>` on a `String` field, for example.
+///
+/// A generics list can be given by prefixing the type with a bracketed list (e.g. `['a]` or
+/// `[T: Clone]`), allowing this macro to be used on generic, lifetime-parameterized, or bounded
+/// structs.
+///
+/// Unlike [`Deref`](std::ops::Deref), a type can implement `AsRef` for multiple targets, so this
+/// macro can be invoked more than once for the same struct.
+///
+/// Also see [`impl_as_mut`].
+///
+/// # Examples
+/// ```
+/// use impl_more::impl_as_ref;
+///
+/// struct Foo(String);
+/// impl_as_ref!(Foo, String);
+///
+/// let foo = Foo("bar".to_owned());
+/// let bar: &String = foo.as_ref();
+/// assert_eq!(bar, "bar");
+/// ```
+///
+/// ```
+/// use impl_more::impl_as_ref;
+///
+/// struct Foo { msg: String }
+/// impl_as_ref!(Foo, String, msg);
+///
+/// let foo = Foo { msg: "bar".to_owned() };
+/// let bar: &String = foo.as_ref();
+/// assert_eq!(bar, "bar");
+/// ```
+///
+/// Forward through the field's own `AsRef` implementation to expose a different target type:
+/// ```
+/// use impl_more::impl_as_ref;
+///
+/// struct Foo(String);
+/// impl_as_ref!(Foo, ref str);
+///
+/// let foo = Foo("bar".to_owned());
+/// let bar: &str = foo.as_ref();
+/// assert_eq!(bar, "bar");
+/// ```
+///
+/// Generic structs, including bounded ones, are supported by prefixing the type with its
+/// bracketed generics list:
+///
+/// ```
+/// use impl_more::impl_as_ref;
+///
+/// struct Wrapper<T: Clone>(T);
+/// impl_as_ref!([T: Clone] Wrapper<T>, T);
+///
+/// let foo = Wrapper(1_i32);
+/// let bar: &i32 = foo.as_ref();
+/// assert_eq!(*bar, 1);
+/// ```
+///
+/// [`AsRef`]: std::convert::AsRef
+/// [`impl_as_mut`]: crate::impl_as_mut
+#[macro_export]
+macro_rules! impl_as_ref {
+    ([$($generics:tt)*] $ty:ty, ref $target:ty) => {
+        impl<$($generics)*> ::core::convert::AsRef<$target> for $ty {
+            fn as_ref(&self) -> &$target {
+                ::core::convert::AsRef::as_ref(&self.0)
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $ty:ty, ref $target:ty, $field:ident) => {
+        impl<$($generics)*> ::core::convert::AsRef<$target> for $ty {
+            fn as_ref(&self) -> &$target {
+                ::core::convert::AsRef::as_ref(&self.$field)
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $ty:ty, $target:ty) => {
+        impl<$($generics)*> ::core::convert::AsRef<$target> for $ty {
+            fn as_ref(&self) -> &$target {
+                &self.0
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $ty:ty, $target:ty, $field:ident) => {
+        impl<$($generics)*> ::core::convert::AsRef<$target> for $ty {
+            fn as_ref(&self) -> &$target {
+                &self.$field
+            }
+        }
+    };
+
+    ($ty:ty, ref $target:ty) => {
+        impl ::core::convert::AsRef<$target> for $ty {
+            fn as_ref(&self) -> &$target {
+                ::core::convert::AsRef::as_ref(&self.0)
+            }
+        }
+    };
+
+    ($ty:ty, ref $target:ty, $field:ident) => {
+        impl ::core::convert::AsRef<$target> for $ty {
+            fn as_ref(&self) -> &$target {
+                ::core::convert::AsRef::as_ref(&self.$field)
+            }
+        }
+    };
+
+    ($ty:ty, $target:ty) => {
+        impl ::core::convert::AsRef<$target> for $ty {
+            fn as_ref(&self) -> &$target {
+                &self.0
+            }
+        }
+    };
+
+    ($ty:ty, $target:ty, $field:ident) => {
+        impl ::core::convert::AsRef<$target> for $ty {
+            fn as_ref(&self) -> &$target {
+                &self.$field
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    struct Foo1(String);
+    impl_as_ref!(Foo1, String);
+
+    #[test]
+    fn foo1_impls() {
+        let foo = Foo1("bar".to_owned());
+        let bar: &String = foo.as_ref();
+        assert_eq!(bar, "bar");
+    }
+
+    struct Foo2 {
+        msg: String,
+    }
+    impl_as_ref!(Foo2, String, msg);
+
+    #[test]
+    fn foo2_impls() {
+        let foo = Foo2 { msg: "bar".to_owned() };
+        let bar: &String = foo.as_ref();
+        assert_eq!(bar, "bar");
+    }
+
+    struct Foo3(String);
+    impl_as_ref!(Foo3, ref str);
+
+    #[test]
+    fn foo3_impls() {
+        let foo = Foo3("bar".to_owned());
+        let bar: &str = foo.as_ref();
+        assert_eq!(bar, "bar");
+    }
+
+    struct Wrapper<T: Clone>(T);
+    impl_as_ref!([T: Clone] Wrapper<T>, T);
+
+    #[test]
+    fn wrapper_impls() {
+        let foo = Wrapper(1_i32);
+        let bar: &i32 = foo.as_ref();
+        assert_eq!(*bar, 1);
+    }
+}
@@ -2,7 +2,11 @@
 ///
 /// The first argument is that of the newtype struct to create the impl for and the second is the
 /// deref target type. The third argument is required for non-newtype structs and is the name of the
-/// field to deref to.
+/// field to deref to, or a tuple index for multi-field tuple structs.
+///
+/// A generics list can be given by prefixing the type with a bracketed list (e.g. `['a]` or
+/// `[T: Clone]`), allowing this macro to be used on generic, lifetime-parameterized, or bounded
+/// structs.
 ///
 /// Also see [`impl_deref_mut`], [`impl_deref_and_mut`], and [`forward_deref_and_mut`].
 ///
@@ -27,12 +31,80 @@
 /// assert_eq!(foo.len(), 3);
 /// ```
 ///
+/// Generic and lifetime-parameterized structs are supported by prefixing the type with its
+/// generics list:
+///
+/// ```
+/// use impl_more::impl_deref;
+///
+/// struct NumRef<'a>(&'a i32);
+/// impl_deref!(['a] NumRef<'a>, i32);
+///
+/// let num = 42;
+/// let foo = NumRef(&num);
+/// assert_eq!(*foo, 42);
+/// ```
+///
+/// Bounds on generic parameters are supported too:
+///
+/// ```
+/// use impl_more::impl_deref;
+///
+/// struct Wrapper<T: Clone>(T);
+/// impl_deref!([T: Clone] Wrapper<T>, T);
+///
+/// let foo = Wrapper(1_i32);
+/// assert_eq!(*foo, 1);
+/// ```
+///
+/// Multi-field tuple structs can deref to any one of their fields by index:
+///
+/// ```
+/// use impl_more::impl_deref;
+///
+/// struct Foo(i32, String);
+/// impl_deref!(Foo, String, 1);
+///
+/// let foo = Foo(1, "bar".to_owned());
+/// assert_eq!(foo.len(), 3);
+/// ```
+///
 /// [`Deref`]: std::ops::Deref
 /// [`impl_deref_mut`]: crate::impl_deref_mut
 /// [`impl_deref_and_mut`]: crate::impl_deref_and_mut
 /// [`forward_deref_and_mut`]: crate::forward_deref_and_mut
 #[macro_export]
 macro_rules! impl_deref {
+    ([$($generics:tt)*] $ty:ty, $target:ty) => {
+        impl<$($generics)*> ::core::ops::Deref for $ty {
+            type Target = $target;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $ty:ty, $target:ty, $field:ident) => {
+        impl<$($generics)*> ::core::ops::Deref for $ty {
+            type Target = $target;
+
+            fn deref(&self) -> &Self::Target {
+                &self.$field
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $ty:ty, $target:ty, $idx:tt) => {
+        impl<$($generics)*> ::core::ops::Deref for $ty {
+            type Target = $target;
+
+            fn deref(&self) -> &Self::Target {
+                &self.$idx
+            }
+        }
+    };
+
     ($ty:ty, $target:ty) => {
         impl ::core::ops::Deref for $ty {
             type Target = $target;
@@ -52,13 +124,27 @@ macro_rules! impl_deref {
             }
         }
     };
+
+    ($ty:ty, $target:ty, $idx:tt) => {
+        impl ::core::ops::Deref for $ty {
+            type Target = $target;
+
+            fn deref(&self) -> &Self::Target {
+                &self.$idx
+            }
+        }
+    };
 }
 
 /// Implement [`DerefMut`] for a struct.
 ///
 /// The first argument is that of the struct to create the impl for and this type must also
-/// implement [`Deref`]. The second argument is required for non-newtype structs and is the field
-/// to deref to.
+/// implement [`Deref`]. The second argument is required for non-newtype structs and is the name
+/// of the field to deref to, or a tuple index for multi-field tuple structs.
+///
+/// A generics list can be given by prefixing the type with a bracketed list (e.g. `['a]` or
+/// `[T: Clone]`), allowing this macro to be used on generic, lifetime-parameterized, or bounded
+/// structs.
 ///
 /// Also see [`impl_deref`], [`impl_deref_and_mut`], and [`forward_deref_and_mut`].
 ///
@@ -77,6 +163,22 @@ macro_rules! impl_deref {
 /// assert_eq!(*foo, "bar!");
 /// ```
 ///
+/// Multi-field tuple structs can deref-mut to any one of their fields by index:
+///
+/// ```
+/// use impl_more::{impl_deref, impl_deref_mut};
+///
+/// struct Foo(i32, String);
+///
+/// impl_deref!(Foo, String, 1);
+/// impl_deref_mut!(Foo, 1);
+///
+/// let mut foo = Foo(1, "bar".to_owned());
+/// foo.push('!');
+///
+/// assert_eq!(*foo, "bar!");
+/// ```
+///
 /// [`Deref`]: std::ops::Deref
 /// [`DerefMut`]: std::ops::DerefMut
 /// [`impl_deref`]: crate::impl_deref
@@ -84,6 +186,30 @@ macro_rules! impl_deref {
 /// [`forward_deref_and_mut`]: crate::forward_deref_and_mut
 #[macro_export]
 macro_rules! impl_deref_mut {
+    ([$($generics:tt)*] $ty:ty) => {
+        impl<$($generics)*> ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $ty:ty, $field:ident) => {
+        impl<$($generics)*> ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.$field
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $ty:ty, $idx:tt) => {
+        impl<$($generics)*> ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.$idx
+            }
+        }
+    };
+
     ($ty:ty) => {
         impl ::core::ops::DerefMut for $ty {
             fn deref_mut(&mut self) -> &mut Self::Target {
@@ -99,6 +225,14 @@ macro_rules! impl_deref_mut {
             }
         }
     };
+
+    ($ty:ty, $idx:tt) => {
+        impl ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.$idx
+            }
+        }
+    };
 }
 
 /// Implements [`Deref`] and [`DerefMut`] by forwarding through an inner field's implementation.
@@ -106,6 +240,10 @@ macro_rules! impl_deref_mut {
 /// Use the `ref <type>` form for deref-ing to types with lifetimes like `&str`. For newtype
 /// structs, only the struct name and deref target type is necessary.
 ///
+/// A generics list can be given by prefixing the type with a bracketed list (e.g. `['a]` or
+/// `[T: Clone]`), allowing this macro to be used on generic, lifetime-parameterized, or bounded
+/// structs.
+///
 /// Also see [`forward_deref_and_mut`].
 ///
 /// # Examples
@@ -125,11 +263,81 @@ macro_rules! impl_deref_mut {
 /// accepts_string_slice(&foo);
 /// ```
 ///
+/// Generic and lifetime-parameterized structs are supported by prefixing the type with its
+/// generics list:
+///
+/// ```
+/// struct Wrapper<'a, T> {
+///     borrowed: &'a mut T,
+///     owned: T,
+/// }
+/// impl_more::impl_deref_and_mut!(['a, T] Wrapper<'a, T>, T, owned);
+/// ```
+///
+/// Multi-field tuple structs can target any one of their fields by index:
+///
+/// ```
+/// struct Foo(i32, String);
+/// impl_more::impl_deref_and_mut!(Foo, String, 1);
+///
+/// let mut foo = Foo(1, "bar".to_owned());
+/// foo.push('!');
+/// assert_eq!(*foo, "bar!");
+/// ```
+///
 /// [`Deref`]: std::ops::Deref
 /// [`DerefMut`]: std::ops::DerefMut
 /// [`forward_deref_and_mut`]: crate::forward_deref_and_mut
 #[macro_export]
 macro_rules! impl_deref_and_mut {
+    ([$($generics:tt)*] $ty:ty, $target:ty) => {
+        impl<$($generics)*> ::core::ops::Deref for $ty {
+            type Target = $target;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl<$($generics)*> ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $ty:ty, $target:ty, $field:ident) => {
+        impl<$($generics)*> ::core::ops::Deref for $ty {
+            type Target = $target;
+
+            fn deref(&self) -> &Self::Target {
+                &self.$field
+            }
+        }
+
+        impl<$($generics)*> ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.$field
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $ty:ty, $target:ty, $idx:tt) => {
+        impl<$($generics)*> ::core::ops::Deref for $ty {
+            type Target = $target;
+
+            fn deref(&self) -> &Self::Target {
+                &self.$idx
+            }
+        }
+
+        impl<$($generics)*> ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.$idx
+            }
+        }
+    };
+
     ($ty:ty, $target:ty) => {
         impl ::core::ops::Deref for $ty {
             type Target = $target;
@@ -161,6 +369,22 @@ macro_rules! impl_deref_and_mut {
             }
         }
     };
+
+    ($ty:ty, $target:ty, $idx:tt) => {
+        impl ::core::ops::Deref for $ty {
+            type Target = $target;
+
+            fn deref(&self) -> &Self::Target {
+                &self.$idx
+            }
+        }
+
+        impl ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.$idx
+            }
+        }
+    };
 }
 
 /// Implements [`Deref`] and [`DerefMut`] by forwarding through an inner field's implementation.
@@ -168,6 +392,10 @@ macro_rules! impl_deref_and_mut {
 /// Use the `ref <type>` form for deref-ing to types with lifetimes like `&str`. For newtype
 /// structs, only the struct name and deref target type is necessary.
 ///
+/// A generics list can be given by prefixing the type with a bracketed list (e.g. `['a]` or
+/// `[T: Clone]`), allowing this macro to be used on generic, lifetime-parameterized, or bounded
+/// structs.
+///
 /// Also see [`impl_deref_and_mut`].
 ///
 /// # Examples
@@ -189,11 +417,203 @@ macro_rules! impl_deref_and_mut {
 /// accepts_mut_string_slice(&foo);
 /// ```
 ///
+/// Generic and lifetime-parameterized structs are supported by prefixing the type with its
+/// generics list:
+///
+/// ```
+/// struct NumRef<'a>(&'a mut i32);
+/// impl_more::forward_deref_and_mut!(['a] NumRef<'a>, i32);
+/// ```
+///
+/// Use the `forward <type>` form when the deref target isn't known up front; it infers `Target`
+/// from the inner field's own `Deref` implementation (given as the type following `forward`)
+/// instead of naming it directly, so a `Box<Vec<T>>` field gives `Target = Vec<T>` without
+/// restating it. Only that one level is taken: `Target` is the inner type's own `Deref::Target`,
+/// not the end of a longer deref chain. Like the `ref <type>` form, `forward` is a prefix on the type
+/// argument rather than a trailing marker, so it can never be confused with a field name:
+///
+/// ```
+/// struct MyBoxedInt(Box<i32>);
+/// impl_more::forward_deref_and_mut!(MyBoxedInt, forward Box<i32>);
+///
+/// let foo = MyBoxedInt(Box::new(1));
+/// let foo_ref: &i32 = &foo;
+///
+/// struct MyStruct { inner: Box<i32> }
+/// impl_more::forward_deref_and_mut!(MyStruct, forward Box<i32>, inner);
+/// ```
+///
+/// Multi-field tuple structs can forward through any one of their fields by index:
+///
+/// ```
+/// struct Foo(i32, String);
+/// impl_more::forward_deref_and_mut!(Foo, ref str, 1);
+///
+/// let foo = Foo(1, "bar".to_owned());
+/// let foo_ref: &str = &foo;
+/// assert_eq!(foo_ref, "bar");
+/// ```
+///
+/// The `forward` form also supports targeting a field by index:
+///
+/// ```
+/// struct Foo(i32, Box<i32>);
+/// impl_more::forward_deref_and_mut!(Foo, forward Box<i32>, 1);
+///
+/// let foo = Foo(1, Box::new(2));
+/// let foo_ref: &i32 = &foo;
+/// assert_eq!(*foo_ref, 2);
+/// ```
+///
 /// [`impl_deref_and_mut`]: crate::impl_deref_and_mut
 /// [`Deref`]: std::ops::Deref
 /// [`DerefMut`]: std::ops::DerefMut
 #[macro_export]
 macro_rules! forward_deref_and_mut {
+    ([$($generics:tt)*] $ty:ty, $target:ty) => {
+        impl<$($generics)*> ::core::ops::Deref for $ty {
+            type Target = $target;
+
+            fn deref(&self) -> &Self::Target {
+                ::core::ops::Deref::deref(&self.0)
+            }
+        }
+
+        impl<$($generics)*> ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                ::core::ops::DerefMut::deref_mut(&mut self.0)
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $ty:ty, ref $target:ty) => {
+        impl<$($generics)*> ::core::ops::Deref for $ty {
+            type Target = $target;
+
+            fn deref(&self) -> &Self::Target {
+                ::core::ops::Deref::deref(&self.0)
+            }
+        }
+
+        impl<$($generics)*> ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                ::core::ops::DerefMut::deref_mut(&mut self.0)
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $ty:ty, forward $inner_ty:ty) => {
+        impl<$($generics)*> ::core::ops::Deref for $ty {
+            type Target = <$inner_ty as ::core::ops::Deref>::Target;
+
+            fn deref(&self) -> &Self::Target {
+                <$inner_ty as ::core::ops::Deref>::deref(&self.0)
+            }
+        }
+
+        impl<$($generics)*> ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                <$inner_ty as ::core::ops::DerefMut>::deref_mut(&mut self.0)
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $ty:ty, $target:ty, $field:ident) => {
+        impl<$($generics)*> ::core::ops::Deref for $ty {
+            type Target = $target;
+
+            fn deref(&self) -> &Self::Target {
+                ::core::ops::Deref::deref(&self.$field)
+            }
+        }
+
+        impl<$($generics)*> ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                ::core::ops::DerefMut::deref_mut(&mut self.$field)
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $ty:ty, forward $inner_ty:ty, $field:ident) => {
+        impl<$($generics)*> ::core::ops::Deref for $ty {
+            type Target = <$inner_ty as ::core::ops::Deref>::Target;
+
+            fn deref(&self) -> &Self::Target {
+                <$inner_ty as ::core::ops::Deref>::deref(&self.$field)
+            }
+        }
+
+        impl<$($generics)*> ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                <$inner_ty as ::core::ops::DerefMut>::deref_mut(&mut self.$field)
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $ty:ty, forward $inner_ty:ty, $idx:tt) => {
+        impl<$($generics)*> ::core::ops::Deref for $ty {
+            type Target = <$inner_ty as ::core::ops::Deref>::Target;
+
+            fn deref(&self) -> &Self::Target {
+                <$inner_ty as ::core::ops::Deref>::deref(&self.$idx)
+            }
+        }
+
+        impl<$($generics)*> ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                <$inner_ty as ::core::ops::DerefMut>::deref_mut(&mut self.$idx)
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $ty:ty, ref $target:ty, $field:ident) => {
+        impl<$($generics)*> ::core::ops::Deref for $ty {
+            type Target = $target;
+
+            fn deref(&self) -> &Self::Target {
+                ::core::ops::Deref::deref(&self.$field)
+            }
+        }
+
+        impl<$($generics)*> ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                ::core::ops::DerefMut::deref_mut(&mut self.$field)
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $ty:ty, $target:ty, $idx:tt) => {
+        impl<$($generics)*> ::core::ops::Deref for $ty {
+            type Target = $target;
+
+            fn deref(&self) -> &Self::Target {
+                ::core::ops::Deref::deref(&self.$idx)
+            }
+        }
+
+        impl<$($generics)*> ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                ::core::ops::DerefMut::deref_mut(&mut self.$idx)
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $ty:ty, ref $target:ty, $idx:tt) => {
+        impl<$($generics)*> ::core::ops::Deref for $ty {
+            type Target = $target;
+
+            fn deref(&self) -> &Self::Target {
+                ::core::ops::Deref::deref(&self.$idx)
+            }
+        }
+
+        impl<$($generics)*> ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                ::core::ops::DerefMut::deref_mut(&mut self.$idx)
+            }
+        }
+    };
+
     ($ty:ty, $target:ty) => {
         impl ::core::ops::Deref for $ty {
             type Target = $target;
@@ -226,6 +646,22 @@ macro_rules! forward_deref_and_mut {
         }
     };
 
+    ($ty:ty, forward $inner_ty:ty) => {
+        impl ::core::ops::Deref for $ty {
+            type Target = <$inner_ty as ::core::ops::Deref>::Target;
+
+            fn deref(&self) -> &Self::Target {
+                <$inner_ty as ::core::ops::Deref>::deref(&self.0)
+            }
+        }
+
+        impl ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                <$inner_ty as ::core::ops::DerefMut>::deref_mut(&mut self.0)
+            }
+        }
+    };
+
     ($ty:ty, $target:ty, $field:ident) => {
         impl ::core::ops::Deref for $ty {
             type Target = $target;
@@ -242,6 +678,38 @@ macro_rules! forward_deref_and_mut {
         }
     };
 
+    ($ty:ty, forward $inner_ty:ty, $field:ident) => {
+        impl ::core::ops::Deref for $ty {
+            type Target = <$inner_ty as ::core::ops::Deref>::Target;
+
+            fn deref(&self) -> &Self::Target {
+                <$inner_ty as ::core::ops::Deref>::deref(&self.$field)
+            }
+        }
+
+        impl ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                <$inner_ty as ::core::ops::DerefMut>::deref_mut(&mut self.$field)
+            }
+        }
+    };
+
+    ($ty:ty, forward $inner_ty:ty, $idx:tt) => {
+        impl ::core::ops::Deref for $ty {
+            type Target = <$inner_ty as ::core::ops::Deref>::Target;
+
+            fn deref(&self) -> &Self::Target {
+                <$inner_ty as ::core::ops::Deref>::deref(&self.$idx)
+            }
+        }
+
+        impl ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                <$inner_ty as ::core::ops::DerefMut>::deref_mut(&mut self.$idx)
+            }
+        }
+    };
+
     ($ty:ty, ref $target:ty, $field:ident) => {
         impl<'__impl_more_a> ::core::ops::Deref for $ty {
             type Target = $target;
@@ -257,6 +725,38 @@ macro_rules! forward_deref_and_mut {
             }
         }
     };
+
+    ($ty:ty, $target:ty, $idx:tt) => {
+        impl ::core::ops::Deref for $ty {
+            type Target = $target;
+
+            fn deref(&self) -> &Self::Target {
+                ::core::ops::Deref::deref(&self.$idx)
+            }
+        }
+
+        impl ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                ::core::ops::DerefMut::deref_mut(&mut self.$idx)
+            }
+        }
+    };
+
+    ($ty:ty, ref $target:ty, $idx:tt) => {
+        impl<'__impl_more_a> ::core::ops::Deref for $ty {
+            type Target = $target;
+
+            fn deref(&self) -> &Self::Target {
+                ::core::ops::Deref::deref(&self.$idx)
+            }
+        }
+
+        impl<'__impl_more_a> ::core::ops::DerefMut for $ty {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                ::core::ops::DerefMut::deref_mut(&mut self.$idx)
+            }
+        }
+    };
 }
 
 #[cfg(test)]
@@ -308,4 +808,162 @@ mod tests {
         accepts_string_slice(&foo);
         accepts_mut_string_slice(&mut foo);
     }
+
+    struct NumRef<'a> {
+        num: &'a mut i32,
+    }
+    impl_deref_and_mut!(['a] NumRef<'a>, i32, num);
+    static_assertions::assert_impl_all!(
+        NumRef<'static>:
+        // impls
+        Deref<Target = i32>,
+        DerefMut<Target = i32>,
+    );
+
+    #[test]
+    fn num_ref_impls() {
+        let mut num = 4;
+        let mut foo = NumRef { num: &mut num };
+        *foo = 7;
+        assert_eq!(*foo, 7);
+    }
+
+    struct BoundedWrapper<T: Clone>(T);
+    impl_deref!([T: Clone] BoundedWrapper<T>, T);
+    static_assertions::assert_impl_all!(
+        BoundedWrapper<i32>:
+        // impls
+        Deref<Target = i32>,
+    );
+
+    #[test]
+    fn bounded_wrapper_impls() {
+        let foo = BoundedWrapper(1);
+        assert_eq!(*foo, 1);
+    }
+
+    struct Foo4(Box<i32>);
+    forward_deref_and_mut!(Foo4, forward Box<i32>);
+    static_assertions::assert_impl_all!(
+        Foo4:
+        // impls
+        Deref<Target = i32>,
+        DerefMut<Target = i32>,
+    );
+
+    #[test]
+    fn foo4_impls() {
+        let mut foo = Foo4(Box::new(1));
+        *foo = 2;
+        assert_eq!(*foo, 2);
+    }
+
+    struct Foo5 {
+        inner: Box<i32>,
+    }
+    forward_deref_and_mut!(Foo5, forward Box<i32>, inner);
+    static_assertions::assert_impl_all!(
+        Foo5:
+        // impls
+        Deref<Target = i32>,
+        DerefMut<Target = i32>,
+    );
+
+    #[test]
+    fn foo5_impls() {
+        let mut foo = Foo5 { inner: Box::new(1) };
+        *foo = 2;
+        assert_eq!(*foo, 2);
+    }
+
+    #[allow(dead_code)]
+    struct Foo6(i32, String);
+    impl_deref!(Foo6, String, 1);
+    impl_deref_mut!(Foo6, 1);
+    static_assertions::assert_impl_all!(
+        Foo6:
+        // impls
+        Deref<Target = String>,
+        DerefMut<Target = String>,
+    );
+
+    #[test]
+    fn foo6_impls() {
+        let mut foo = Foo6(1, "bar".to_owned());
+        foo.push('!');
+        assert_eq!(*foo, "bar!");
+    }
+
+    #[allow(dead_code)]
+    struct Foo7(i32, String);
+    forward_deref_and_mut!(Foo7, ref str, 1);
+    static_assertions::assert_impl_all!(
+        Foo7:
+        // impls
+        Deref,
+        DerefMut,
+    );
+
+    #[test]
+    fn foo7_impls() {
+        let mut foo = Foo7(1, "bar".to_owned());
+        accepts_string_slice(&foo);
+        accepts_mut_string_slice(&mut foo);
+    }
+
+    // regression test: a field literally named `forward` must still be treated as a field name,
+    // not misparsed as the `forward <type>` marker.
+    struct Foo8 {
+        forward: String,
+    }
+    forward_deref_and_mut!(Foo8, ref str, forward);
+    static_assertions::assert_impl_all!(
+        Foo8:
+        // impls
+        Deref,
+        DerefMut,
+    );
+
+    #[test]
+    fn foo8_impls() {
+        let mut foo = Foo8 {
+            forward: "bar".to_owned(),
+        };
+        accepts_string_slice(&foo);
+        accepts_mut_string_slice(&mut foo);
+    }
+
+    #[allow(dead_code)]
+    struct Foo9(i32, Box<i32>);
+    forward_deref_and_mut!(Foo9, forward Box<i32>, 1);
+    static_assertions::assert_impl_all!(
+        Foo9:
+        // impls
+        Deref<Target = i32>,
+        DerefMut<Target = i32>,
+    );
+
+    #[test]
+    fn foo9_impls() {
+        let mut foo = Foo9(1, Box::new(2));
+        *foo = 3;
+        assert_eq!(*foo, 3);
+    }
+
+    #[allow(dead_code)]
+    struct Foo10(i32, String);
+    impl_deref_and_mut!(Foo10, String, 1);
+    static_assertions::assert_impl_all!(
+        Foo10:
+        // impls
+        Deref<Target = String>,
+        DerefMut<Target = String>,
+    );
+
+    #[test]
+    fn foo10_impls() {
+        let mut foo = Foo10(1, "bar".to_owned());
+        foo.push('!');
+        assert_eq!(*foo, "bar!");
+    }
 }
@@ -0,0 +1,132 @@
+/// Implement [`From`] for a struct, constructing it from its inner value.
+///
+/// The first argument is that of the newtype struct to create the impl for and the second is the
+/// type of the inner value. The third argument is required for non-newtype structs and is the
+/// name of the field to construct.
+///
+/// A generics list can be given by prefixing the type with a bracketed list (e.g. `['a]` or
+/// `[T: Clone]`), allowing this macro to be used on generic, lifetime-parameterized, or bounded
+/// structs.
+///
+/// # Examples
+/// ```
+/// use impl_more::impl_from;
+///
+/// struct Foo(String);
+/// impl_from!(Foo, String);
+///
+/// let foo = Foo::from("bar".to_owned());
+/// assert_eq!(foo.0, "bar");
+/// ```
+///
+/// ```
+/// use impl_more::impl_from;
+///
+/// struct Foo { msg: String }
+/// impl_from!(Foo, String, msg);
+///
+/// let foo = Foo::from("bar".to_owned());
+/// assert_eq!(foo.msg, "bar");
+/// ```
+///
+/// Generic and lifetime-parameterized structs are supported by prefixing the type with its
+/// generics list:
+///
+/// ```
+/// use impl_more::impl_from;
+///
+/// struct Wrapper<T>(T);
+/// impl_from!([T] Wrapper<T>, T);
+///
+/// let wrapper = Wrapper::from(1_i32);
+/// assert_eq!(wrapper.0, 1);
+/// ```
+///
+/// Bounds on generic parameters are supported too:
+///
+/// ```
+/// use impl_more::impl_from;
+///
+/// struct Wrapper<T: Clone>(T);
+/// impl_from!([T: Clone] Wrapper<T>, T);
+///
+/// let wrapper = Wrapper::from(1_i32);
+/// assert_eq!(wrapper.0, 1);
+/// ```
+///
+/// [`From`]: std::convert::From
+#[macro_export]
+macro_rules! impl_from {
+    ([$($generics:tt)*] $ty:ty, $inner_ty:ty) => {
+        impl<$($generics)*> ::core::convert::From<$inner_ty> for $ty {
+            fn from(inner: $inner_ty) -> Self {
+                Self(inner)
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $ty:ty, $inner_ty:ty, $field:ident) => {
+        impl<$($generics)*> ::core::convert::From<$inner_ty> for $ty {
+            fn from(inner: $inner_ty) -> Self {
+                Self { $field: inner }
+            }
+        }
+    };
+
+    ($ty:ty, $inner_ty:ty) => {
+        impl ::core::convert::From<$inner_ty> for $ty {
+            fn from(inner: $inner_ty) -> Self {
+                Self(inner)
+            }
+        }
+    };
+
+    ($ty:ty, $inner_ty:ty, $field:ident) => {
+        impl ::core::convert::From<$inner_ty> for $ty {
+            fn from(inner: $inner_ty) -> Self {
+                Self { $field: inner }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    struct Foo1(String);
+    impl_from!(Foo1, String);
+
+    #[test]
+    fn foo1_impls() {
+        let foo = Foo1::from("bar".to_owned());
+        assert_eq!(foo.0, "bar");
+    }
+
+    struct Foo2 {
+        msg: String,
+    }
+    impl_from!(Foo2, String, msg);
+
+    #[test]
+    fn foo2_impls() {
+        let foo = Foo2::from("bar".to_owned());
+        assert_eq!(foo.msg, "bar");
+    }
+
+    struct Wrapper<T>(T);
+    impl_from!([T] Wrapper<T>, T);
+
+    #[test]
+    fn wrapper_impls() {
+        let wrapper = Wrapper::from(1_i32);
+        assert_eq!(wrapper.0, 1);
+    }
+
+    struct BoundedWrapper<T: Clone>(T);
+    impl_from!([T: Clone] BoundedWrapper<T>, T);
+
+    #[test]
+    fn bounded_wrapper_impls() {
+        let wrapper = BoundedWrapper::from(1_i32);
+        assert_eq!(wrapper.0, 1);
+    }
+}
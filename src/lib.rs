@@ -0,0 +1,9 @@
+//! Concise, declarative macros for implementing common, well-known traits on newtype structs and
+//! on structs that wrap one of their fields, without the overhead of a derive macro.
+
+#![forbid(unsafe_code)]
+
+mod as_mut;
+mod as_ref;
+mod deref;
+mod from;